@@ -27,22 +27,30 @@ enum Error {
 /// So... TTT = 0, TTC = 1, TTA = 2, ... , GGC = 61, GGA = 62, GGG = 63
 const GENETIC_CODE: &[u8] = b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
 
-/// some codons can be used as Met in the start position
-const STARTS: &[u8] = b"---M------**--*----M------------MMMM---------------M------------";
-
-/// ERR_BAD_NT is an error value for an invalid nucleotide
-const ERR_BAD_NT: usize = 99;
-
-/// map a base for indexing the GENETIC_CODE string
-/// x (u8): base to look up
-/// returns: usize
-fn lookup(x: u8) -> usize {
+/// map an IUPAC base code to the set of concrete bases it represents, given
+/// as GENETIC_CODE-index components (T=0,C=1,A=2,G=3). Unambiguous bases
+/// map to a single component; degenerate/ambiguous IUPAC codes map to two
+/// or more. Unrecognized symbols return None.
+/// x (u8): base code to look up
+/// returns: Option<Vec<usize>>
+fn lookup(x: u8) -> Option<Vec<usize>> {
     match x {
-        b'T' => 0,          // T 00
-        b'C' => 1,          // C 01
-        b'A' | b'N' => 2,   // A | N 10
-        b'G' => 3,          // G 11
-        _ => ERR_BAD_NT,    // unknown base
+        b'T' => Some(vec![0]),
+        b'C' => Some(vec![1]),
+        b'A' => Some(vec![2]),
+        b'G' => Some(vec![3]),
+        b'R' => Some(vec![2, 3]),       // A, G
+        b'Y' => Some(vec![1, 0]),       // C, T
+        b'S' => Some(vec![3, 1]),       // G, C
+        b'W' => Some(vec![2, 0]),       // A, T
+        b'K' => Some(vec![3, 0]),       // G, T
+        b'M' => Some(vec![2, 1]),       // A, C
+        b'B' => Some(vec![1, 3, 0]),    // C, G, T
+        b'D' => Some(vec![2, 3, 0]),    // A, G, T
+        b'H' => Some(vec![2, 1, 0]),    // A, C, T
+        b'V' => Some(vec![2, 1, 3]),    // A, C, G
+        b'N' => Some(vec![0, 1, 2, 3]), // A, C, G, T
+        _ => None,                      // unknown base
     }
 }
 
@@ -92,31 +100,144 @@ fn three_letter_code(aa: char) -> Result<String, Error> {
     }
 }
 
-/// translate a codon into its corresponding amino acid
+/// the four IUPAC unambiguous bases, in the T=0,C=1,A=2,G=3 order used to
+/// index GENETIC_CODE and every table in GENETIC_CODE_TABLES
+const BASES: [u8; 4] = [b'T', b'C', b'A', b'G'];
+
+/// build a 64-char Starts string from an AAs string and a list of the
+/// codons that may serve as alternative start codons, following the same
+/// convention NCBI uses: 'M' at start-codon positions, '*' at stop
+/// positions (copied from `aas`), '-' everywhere else.
+fn build_starts(aas: &[u8], start_codons: &[&str]) -> Vec<u8> {
+    (0..64)
+        .map(|i| {
+            if aas[i] == b'*' {
+                b'*'
+            } else {
+                let codon = [BASES[i / 16], BASES[(i / 4) % 4], BASES[i % 4]];
+                if start_codons.iter().any(|c| c.as_bytes() == codon) {
+                    b'M'
+                } else {
+                    b'-'
+                }
+            }
+        })
+        .collect()
+}
+
+/// a single registry entry: (amino-acid table, start-codon table)
+type CodeTable = (Vec<u8>, Vec<u8>);
+
+/// registry of NCBI genetic code tables, keyed by transl_table id (see
+/// https://www.ncbi.nlm.nih.gov/Taxonomy/Utils/wprintgc.cgi). Each entry is
+/// the familiar pair of 64-character strings (amino acids, starts) in the
+/// same T=0,C=1,A=2,G=3, index = 16*b0 + 4*b1 + b2 layout as GENETIC_CODE
+/// above; the starts string is generated by build_starts.
+static GENETIC_CODE_TABLES: Lazy<HashMap<u8, CodeTable>> = Lazy::new(|| {
+    let mut m: HashMap<u8, CodeTable> = HashMap::new();
+
+    // 1. Standard
+    let aas = GENETIC_CODE.to_vec();
+    let starts = build_starts(&aas, &["TTG", "CTG", "ATG"]);
+    m.insert(1, (aas, starts));
+
+    // 2. Vertebrate Mitochondrial
+    let aas = b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG".to_vec();
+    let starts = build_starts(&aas, &["ATT", "ATC", "ATA", "ATG", "GTG"]);
+    m.insert(2, (aas, starts));
+
+    // 3. Yeast Mitochondrial
+    let aas = b"FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG".to_vec();
+    let starts = build_starts(&aas, &["ATA", "ATG"]);
+    m.insert(3, (aas, starts));
+
+    // 4. Mold, Protozoan and Coelenterate Mitochondrial & Mycoplasma/Spiroplasma
+    let aas = b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG".to_vec();
+    let starts = build_starts(
+        &aas,
+        &["TTA", "TTG", "CTG", "ATT", "ATC", "ATA", "ATG", "GTG"],
+    );
+    m.insert(4, (aas, starts));
+
+    // 5. Invertebrate Mitochondrial
+    let aas = b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG".to_vec();
+    let starts = build_starts(&aas, &["TTG", "ATT", "ATC", "ATA", "ATG", "GTG"]);
+    m.insert(5, (aas, starts));
+
+    // 6. Ciliate, Dasycladacean and Hexamita Nuclear
+    let aas = b"FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG".to_vec();
+    let starts = build_starts(&aas, &["ATG"]);
+    m.insert(6, (aas, starts));
+
+    // 11. Bacterial, Archaeal and Plant Plastid
+    let aas = GENETIC_CODE.to_vec();
+    let starts = build_starts(&aas, &["TTG", "CTG", "ATT", "ATC", "ATA", "ATG", "GTG"]);
+    m.insert(11, (aas, starts));
+
+    m
+});
+
+/// look up a translation table by its transl_table id, falling back to
+/// table 11 (the tool's long-standing default) if the id is unknown
+fn lookup_table(id: u8) -> (&'static [u8], &'static [u8]) {
+    let entry = GENETIC_CODE_TABLES.get(&id).unwrap_or_else(|| {
+        eprintln!(
+            "Warning: unknown translation table {}; falling back to table 11.",
+            id
+        );
+        &GENETIC_CODE_TABLES[&11]
+    });
+    (entry.0.as_slice(), entry.1.as_slice())
+}
+
+/// translate a codon into its corresponding amino acid. IUPAC
+/// degenerate/ambiguous bases are handled by enumerating every concrete
+/// codon they imply and requiring all of them to agree on a translation;
+/// otherwise the codon is reported as 'X' (unknown). This yields the usual
+/// "compressed" behavior, eg GTN -> Val and ATH -> Ile, even though
+/// individual positions are ambiguous.
 /// triplet (&[u8]): a three-letter codon eg "ATG"
-/// i (usize): codon position. if 0, use the STARTS table
+/// i (usize): codon position. if 0, use the starts table
+/// code (&[u8]): the GENETIC_CODE-style AAs table to translate against
+/// starts (&[u8]): the start-codon table (see build_starts) to consult when i == 0
 /// returns: Result<char, Error>
-fn translate(triplet: &[u8], i: usize) -> Result<char, Error> {
-    let mut codon = vec![ERR_BAD_NT; 3];
+fn translate(triplet: &[u8], i: usize, code: &[u8], starts: &[u8]) -> Result<char, Error> {
+    let mut base_sets: Vec<Vec<usize>> = Vec::with_capacity(3);
+    let mut bad_positions = Vec::new();
+
+    for (pos, base) in triplet.iter().enumerate() {
+        match lookup(*base) {
+            Some(set) => base_sets.push(set),
+            None => bad_positions.push(pos),
+        }
+    }
 
-    for (i, base) in triplet.iter().enumerate() {
-        codon[i] = lookup(*base);
+    if !bad_positions.is_empty() {
+        return Err(Error::BadNucleotide(bad_positions));
     }
 
-    if codon.contains(&ERR_BAD_NT) {
-        return Err(Error::BadNucleotide(codon));
+    // enumerate every concrete codon implied by the (possibly ambiguous)
+    // input bases
+    let mut indices = Vec::new();
+    for &b0 in &base_sets[0] {
+        for &b1 in &base_sets[1] {
+            for &b2 in &base_sets[2] {
+                indices.push((b0 * 16) + (b1 * 4) + b2);
+            }
+        }
     }
 
-    let index: usize = (codon[0] * 16) + (codon[1] * 4) + codon[2];
-    // translate the codon into single-letter code
+    let all_agree = |table: &[u8]| indices.iter().all(|&idx| table[idx] == table[indices[0]]);
 
-    let c = if (i == 0) && (STARTS[index] == b'M') {
-        b'M'
-    } else {
-        GENETIC_CODE[index]
-    };
+    if (i == 0) && all_agree(starts) && (starts[indices[0]] == b'M') {
+        return Ok('M');
+    }
 
-    Ok(c as char)
+    Ok(if all_agree(code) {
+        code[indices[0]] as char
+    } else {
+        'X'
+    })
 }
 
 /// print a pretty DNA sequence and its translation, plus line numbering
@@ -125,9 +246,11 @@ fn translate(triplet: &[u8], i: usize) -> Result<char, Error> {
 ///
 /// s (&str): DNA sequence to print
 /// one_letter (bool): use one-letter amino acid code
-/// 
+/// code (&[u8]): the GENETIC_CODE-style AAs table to translate against
+/// starts (&[u8]): the start-codon table (see build_starts) to consult at codon position 0
+///
 /// returns: Result<(), Error>
-fn print_seq(s: &str, one_letter: bool) -> Result<(), Error> {
+fn print_seq(s: &str, one_letter: bool, code: &[u8], starts: &[u8]) -> Result<(), Error> {
     let line_len = 72; // print 72 bases per line (24 amino acids)
 
     // how many lines to print
@@ -144,7 +267,7 @@ fn print_seq(s: &str, one_letter: bool) -> Result<(), Error> {
     let mut peptide = String::new();
     // use chunks_exact() in case a sequence ends on a partial-length codon
     for (i, codon) in s.as_bytes().chunks_exact(3).enumerate() {
-        let aa = translate(codon, i)?;
+        let aa = translate(codon, i, code, starts)?;
         // translate and add to the string
         if one_letter {
             // for one-letter code, insert a space b/w each residue,
@@ -184,6 +307,390 @@ fn print_seq(s: &str, one_letter: bool) -> Result<(), Error> {
     Ok(())
 }
 
+/// write a single FASTA record, wrapping the sequence at 60 columns/line
+/// out (&mut impl Write): destination to write the record to
+/// id (&str): sequence identifier, eg a protein_id
+/// desc (&str): free-text description appended to the header, eg a product
+/// seq (&str): the sequence itself (nucleotide or peptide)
+/// returns: io::Result<()>
+fn write_fasta_record(out: &mut impl Write, id: &str, desc: &str, seq: &str) -> io::Result<()> {
+    writeln!(out, ">{} {}", id, desc)?;
+    for line in seq.as_bytes().chunks(60) {
+        out.write_all(line)?;
+        out.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// complement a single IUPAC base code
+/// b (u8): base to complement
+/// returns: u8
+fn complement_base(b: u8) -> u8 {
+    match b {
+        b'A' => b'T',
+        b'T' => b'A',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'R' => b'Y', // A/G <-> C/T
+        b'Y' => b'R',
+        b'S' => b'S', // G/C is self-complementary
+        b'W' => b'W', // A/T is self-complementary
+        b'K' => b'M', // G/T <-> A/C
+        b'M' => b'K',
+        b'B' => b'V', // C/G/T <-> A/C/G
+        b'V' => b'B',
+        b'D' => b'H', // A/G/T <-> A/C/T
+        b'H' => b'D',
+        b'N' => b'N',
+        other => other,
+    }
+}
+
+/// reverse-complement a DNA sequence, honoring IUPAC ambiguity codes
+/// s (&str): uppercase sequence to reverse-complement
+/// returns: String
+fn reverse_complement(s: &str) -> String {
+    s.bytes()
+        .rev()
+        .map(complement_base)
+        .map(|b| b as char)
+        .collect()
+}
+
+/// find open reading frames in a translated peptide: stretches starting at
+/// a start Met ('M') and ending at the next stop ('*'), inclusive
+/// peptide (&str): translated amino-acid sequence to scan
+/// returns: Vec<(usize, usize)> of (start, end) amino-acid indices, 0-based inclusive
+fn find_orfs(peptide: &str) -> Vec<(usize, usize)> {
+    let mut orfs = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (i, aa) in peptide.chars().enumerate() {
+        match (start, aa) {
+            (None, 'M') => start = Some(i),
+            (Some(s), '*') => {
+                orfs.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    orfs
+}
+
+/// translate a sequence in all six reading frames (three forward, three
+/// reverse-complement) and print each, labelled +1/+2/+3/-1/-2/-3, reusing
+/// print_seq for the numbering/layout of every frame
+/// s (&str): the uppercase DNA sequence to translate
+/// one_letter (bool): use one-letter amino acid code
+/// code (&[u8]) / starts (&[u8]): translation table to translate against
+/// show_orfs (bool): also report ORFs (start Met .. next stop) per frame
+/// returns: Result<(), Error>
+fn six_frame_translate(
+    s: &str,
+    one_letter: bool,
+    code: &[u8],
+    starts: &[u8],
+    show_orfs: bool,
+) -> Result<(), Error> {
+    let rc = reverse_complement(s);
+
+    let frames: [(&str, &str); 6] = [
+        ("+1", s),
+        ("+2", &s[1.min(s.len())..]),
+        ("+3", &s[2.min(s.len())..]),
+        ("-1", &rc),
+        ("-2", &rc[1.min(rc.len())..]),
+        ("-3", &rc[2.min(rc.len())..]),
+    ];
+
+    for (i, (label, frame_seq)) in frames.iter().enumerate() {
+        let offset = i % 3;
+
+        println!("\n=== Frame {} ===", label);
+        print_seq(frame_seq, one_letter, code, starts)?;
+
+        if show_orfs {
+            let mut peptide = String::new();
+            for (j, codon) in frame_seq.as_bytes().chunks_exact(3).enumerate() {
+                peptide.push(translate(codon, j, code, starts)?);
+            }
+
+            let orfs = find_orfs(&peptide);
+            if orfs.is_empty() {
+                println!("No ORFs found in frame {}.", label);
+            } else {
+                for (start, end) in orfs {
+                    let nt_start = offset + (start * 3) + 1;
+                    let nt_end = offset + (end * 3) + 3;
+                    println!(
+                        "ORF in frame {}: aa {}-{} (nt {}-{})",
+                        label,
+                        start + 1,
+                        end + 1,
+                        nt_start,
+                        nt_end
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// compute basic sequence composition: a count of each base present, and
+/// the GC content (fraction of G+C over unambiguous A/C/G/T bases, ignoring
+/// any IUPAC ambiguity symbols)
+/// s (&str): uppercased sequence to analyze
+/// returns: (HashMap<char, usize>, f64) of (base counts, GC fraction)
+fn composition_stats(s: &str) -> (HashMap<char, usize>, f64) {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    let mut gc = 0usize;
+    let mut at = 0usize;
+
+    for b in s.chars() {
+        *counts.entry(b).or_insert(0) += 1;
+        match b {
+            'G' | 'C' => gc += 1,
+            'A' | 'T' => at += 1,
+            _ => {} // ignore ambiguous symbols
+        }
+    }
+
+    let gc_fraction = if gc + at > 0 {
+        gc as f64 / (gc + at) as f64
+    } else {
+        0.0
+    };
+
+    (counts, gc_fraction)
+}
+
+/// codon usage grouped by amino acid: for each amino acid, the codons
+/// observed that encode it, each as (codon, count, frequency-in-sequence)
+type CodonUsage = HashMap<char, Vec<(String, usize, f64)>>;
+
+/// tally codon usage across a sequence: count and relative frequency of
+/// each codon observed, grouped by the amino acid it encodes under `code`
+/// s (&str): uppercased sequence to analyze, chunked via chunks_exact(3) as
+///   print_seq does
+/// code (&[u8]) / starts (&[u8]): translation table to translate against
+/// returns: Result<CodonUsage, Error>
+fn codon_usage(s: &str, code: &[u8], starts: &[u8]) -> Result<CodonUsage, Error> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut total = 0usize;
+
+    for codon in s.as_bytes().chunks_exact(3) {
+        *counts
+            .entry(String::from_utf8_lossy(codon).to_string())
+            .or_insert(0) += 1;
+        total += 1;
+    }
+
+    let mut by_aa: HashMap<char, Vec<(String, usize, f64)>> = HashMap::new();
+    for (codon, count) in counts {
+        // translate at position 1 (not 0) so the start-codon special case
+        // doesn't file an alternative start codon (eg GTG) under 'M' instead
+        // of the amino acid it encodes
+        let aa = translate(codon.as_bytes(), 1, code, starts)?;
+        let freq = count as f64 / total as f64;
+        by_aa.entry(aa).or_default().push((codon, count, freq));
+    }
+
+    Ok(by_aa)
+}
+
+/// print a compositional profile for a sequence: length, base counts, GC
+/// content, and a codon-usage table grouped by amino acid
+/// label (&str): heading describing what's being analyzed, eg a record or CDS name
+/// s (&str): uppercased sequence to analyze
+/// code (&[u8]) / starts (&[u8]): translation table to translate against
+/// returns: Result<(), Error>
+fn print_stats(label: &str, s: &str, code: &[u8], starts: &[u8]) -> Result<(), Error> {
+    let (counts, gc_fraction) = composition_stats(s);
+
+    println!("\n{}", label);
+    println!("Length: {} bases", s.len());
+    println!("GC content: {:.2}%", gc_fraction * 100.0);
+
+    let mut bases: Vec<&char> = counts.keys().collect();
+    bases.sort();
+    for b in bases {
+        println!("  {}: {}", b, counts[b]);
+    }
+
+    println!("Codon usage:");
+    let usage = codon_usage(s, code, starts)?;
+    let mut aas: Vec<&char> = usage.keys().collect();
+    aas.sort();
+
+    for aa in aas {
+        let mut codons = usage[aa].clone();
+        codons.sort_by(|a, b| a.0.cmp(&b.0));
+        for (codon, count, freq) in codons {
+            println!("  {} ({}) {:>3}  {:>6.2}%", codon, aa, count, freq * 100.0);
+        }
+    }
+
+    Ok(())
+}
+
+/// build a suffix array for `s` by directly sorting all suffixes by
+/// content. Simple and O(n^2 log n); fine for the gene/window-sized
+/// sequences this tool otherwise works with.
+/// s (&[u8]): sequence to index
+/// returns: Vec<usize> of starting positions, in sorted (suffix-array) order
+fn build_suffix_array(s: &[u8]) -> Vec<usize> {
+    let mut sa: Vec<usize> = (0..s.len()).collect();
+    sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+    sa
+}
+
+/// build the LCP (longest-common-prefix) array between adjacent suffixes in
+/// suffix-array order, via Kasai's algorithm
+/// s (&[u8]): the sequence the suffix array was built over
+/// sa (&[usize]): suffix array for `s`
+/// returns: Vec<usize>, lcp[r] = length of the common prefix shared between
+///   the suffixes at ranks r-1 and r (lcp[0] is 0, there being no predecessor)
+fn build_lcp_array(s: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut rank = vec![0usize; n];
+    for (r, &suffix) in sa.iter().enumerate() {
+        rank[suffix] = r;
+    }
+
+    let mut lcp = vec![0usize; n];
+    let mut h = 0usize;
+
+    for i in 0..n {
+        if rank[i] > 0 {
+            let j = sa[rank[i] - 1];
+            while (i + h < n) && (j + h < n) && (s[i + h] == s[j + h]) {
+                h += 1;
+            }
+            lcp[rank[i]] = h;
+            h = h.saturating_sub(1);
+        } else {
+            h = 0;
+        }
+    }
+
+    lcp
+}
+
+/// compute the shortest locally-unique substring starting at each position
+/// of `s`, via its suffix array and LCP array: for the suffix at rank r,
+/// its shortest unique prefix is one base longer than the longer of the
+/// prefixes it shares with its suffix-array neighbours, ie
+/// max(lcp[r], lcp[r+1]) + 1. If that length would run past `region_len`,
+/// no unique substring starts at that position (the remaining bases are
+/// still a prefix of some other suffix, so None is recorded rather than a
+/// truncated, falsely-unique length). `region_len` bounds candidates to
+/// the caller's region of interest (eg `s` alone, not an appended
+/// reverse complement), so a length is never accepted that spills past
+/// the region the caller will actually slice.
+/// s (&[u8]): sequence to analyze (may extend past region_len, eg to
+///   include an appended reverse complement for both-strand uniqueness)
+/// region_len (usize): candidate lengths must fit within this many bases
+///   of their starting position
+/// returns: Vec<Option<usize>> indexed by starting position, giving the
+///   shortest unique length at that position, or None if none exists
+fn shortest_unique_lengths(s: &[u8], region_len: usize) -> Vec<Option<usize>> {
+    let n = s.len();
+    let sa = build_suffix_array(s);
+    let lcp = build_lcp_array(s, &sa);
+
+    let mut lengths = vec![None; n];
+    for (r, &pos) in sa.iter().enumerate() {
+        let left = lcp[r];
+        let right = if r + 1 < n { lcp[r + 1] } else { 0 };
+        let len = cmp::max(left, right) + 1;
+        if pos < region_len && len <= region_len - pos {
+            lengths[pos] = Some(len);
+        }
+    }
+
+    lengths
+}
+
+/// find the position (within the first `region_len` bytes of `s`) and
+/// length of the shortest locally-unique substring
+/// region_len (usize): only positions before this are considered as
+///   candidates, and candidate lengths must not spill past it either
+/// s (&[u8]): full sequence to index (may extend past region_len, eg to
+///   include an appended reverse complement for both-strand uniqueness)
+/// returns: Option<(usize, usize)> of (0-based start position, length)
+fn shortest_unique_in_region(region_len: usize, s: &[u8]) -> Option<(usize, usize)> {
+    let lengths = shortest_unique_lengths(s, region_len);
+    lengths[..region_len]
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, &len)| len.map(|len| (pos, len)))
+        .min_by_key(|&(_, len)| len)
+}
+
+/// find the shortest subsequence of `s` that is unique within `s` itself
+/// s (&str): uppercased sequence (a CDS or window) to search
+/// returns: Option<(String, usize, usize)> of (k-mer, 1-based start, length)
+fn shortest_unique_subsequence(s: &str) -> Option<(String, usize, usize)> {
+    let bytes = s.as_bytes();
+    let (pos, len) = shortest_unique_in_region(bytes.len(), bytes)?;
+    Some((s[pos..pos + len].to_string(), pos + 1, len))
+}
+
+/// find the shortest subsequence of `s` that is unique against both `s`
+/// and its reverse complement, so the resulting k-mer won't also bind the
+/// opposite strand elsewhere in the region
+/// s (&str): uppercased sequence (a CDS or window) to search
+/// returns: Option<(String, usize, usize)> of (k-mer, 1-based start, length)
+fn shortest_unique_subsequence_both_strands(s: &str) -> Option<(String, usize, usize)> {
+    let combined = format!("{}${}", s, reverse_complement(s));
+    let (pos, len) = shortest_unique_in_region(s.len(), combined.as_bytes())?;
+    Some((s[pos..pos + len].to_string(), pos + 1, len))
+}
+
+/// report the shortest locally-unique subsequence within a CDS or window,
+/// in the same 1-based coordinates print_seq already uses
+/// label (&str): heading describing what's being searched, eg a CDS or window
+/// s (&str): uppercased sequence to search
+/// both_strands (bool): also require uniqueness against the reverse complement
+fn report_unique_subsequence(label: &str, s: &str, both_strands: bool) {
+    let result = if both_strands {
+        shortest_unique_subsequence_both_strands(s)
+    } else {
+        shortest_unique_subsequence(s)
+    };
+
+    match result {
+        Some((kmer, start, len)) => println!(
+            "{}: shortest unique subsequence is '{}' ({} bp) at position {}",
+            label, kmer, len, start
+        ),
+        None => println!("{}: no unique subsequence found.", label),
+    }
+}
+
+/// parse a "<start>-<end>" 1-based inclusive window spec
+/// spec (&str): eg "100-250"
+/// returns: Result<(usize, usize), String>
+fn parse_window(spec: &str) -> Result<(usize, usize), String> {
+    let parts: Vec<&str> = spec.split('-').collect();
+    if parts.len() != 2 {
+        return Err("expected <start>-<end>".to_string());
+    }
+
+    let start: usize = parts[0].parse().map_err(|_| "invalid start".to_string())?;
+    let end: usize = parts[1].parse().map_err(|_| "invalid end".to_string())?;
+
+    if start == 0 || end < start {
+        return Err("start must be >= 1 and end >= start".to_string());
+    }
+
+    Ok((start, end))
+}
+
 /// count the digits in a number
 /// n (u16): number to count
 /// returns: usize
@@ -222,10 +729,79 @@ fn main() {
                 .takes_value(false)
                 .help("Use single-letter translation"),
         )
+        .arg(
+            Arg::with_name("table")
+                .short("t")
+                .long("table")
+                .value_name("N")
+                .takes_value(true)
+                .help("NCBI translation table id to use (default: 11, or a CDS's own /transl_table)"),
+        )
+        .arg(
+            Arg::with_name("fasta")
+                .long("fasta")
+                .visible_alias("extract")
+                .value_name("DIR")
+                .takes_value(true)
+                .help("Non-interactively extract every CDS to nucleotide/protein FASTA files in DIR"),
+        )
+        .arg(
+            Arg::with_name("six-frame")
+                .long("six-frame")
+                .takes_value(false)
+                .help("Translate every record in all six reading frames (+1/+2/+3/-1/-2/-3)"),
+        )
+        .arg(
+            Arg::with_name("orfs")
+                .long("orfs")
+                .takes_value(false)
+                .requires("six-frame")
+                .help("With --six-frame, also report open reading frames (M...* ) and their coordinates"),
+        )
+        .arg(
+            Arg::with_name("stats")
+                .long("stats")
+                .takes_value(false)
+                .help("Print sequence composition (GC content, base counts, codon usage) per record and CDS"),
+        )
+        .arg(
+            Arg::with_name("unique")
+                .long("unique")
+                .takes_value(false)
+                .help("Find the shortest locally-unique subsequence per CDS (or --window), for candidate primer/probe design"),
+        )
+        .arg(
+            Arg::with_name("window")
+                .long("window")
+                .value_name("START-END")
+                .takes_value(true)
+                .requires("unique")
+                .help("With --unique, search only the 1-based inclusive region START-END instead of every CDS"),
+        )
+        .arg(
+            Arg::with_name("both-strands")
+                .long("both-strands")
+                .takes_value(false)
+                .requires("unique")
+                .help("With --unique, require uniqueness against the reverse complement too"),
+        )
         .get_matches();
 
     let one_letter = matches.is_present("one-letter");
 
+    // if the user passed --table explicitly, it overrides any per-CDS
+    // /transl_table qualifier; otherwise each gene picks its own table
+    let table_override: Option<u8> = match matches.value_of("table") {
+        None => None,
+        Some(t) => match t.parse::<u8>() {
+            Ok(n) => Some(n),
+            Err(_) => {
+                println!("Invalid table id: '{}'", t);
+                process::exit(1);
+            }
+        },
+    };
+
     let filename = match matches.value_of("infile") {
         None => "nc_005816.gb",
         Some(file_path) => file_path,
@@ -236,6 +812,233 @@ fn main() {
         process::exit(1);
     }
 
+    if let Some(outdir) = matches.value_of("fasta") {
+        std::fs::create_dir_all(outdir).unwrap_or_else(|e| {
+            println!("Could not create output directory '{}': {}", outdir, e);
+            process::exit(1);
+        });
+
+        let nt_path = std::path::Path::new(outdir).join("cds_nt.fasta");
+        let aa_path = std::path::Path::new(outdir).join("cds_protein.fasta");
+        let mut nt_out = File::create(&nt_path).unwrap();
+        let mut aa_out = File::create(&aa_path).unwrap();
+
+        let mut n_records = 0;
+        let mut n_cds = 0;
+        let file = File::open(filename).unwrap();
+        for r in SeqReader::new(file) {
+            let seq = r.unwrap();
+
+            for f in &seq.features {
+                if f.kind != feature_kind!("CDS") {
+                    continue;
+                }
+
+                let protein_id = f
+                    .qualifier_values(qualifier_key!("protein_id"))
+                    .next()
+                    .map(|v| v.to_string().replace('\n', ""))
+                    .unwrap_or_else(|| "unknown".to_string());
+                let product = f
+                    .qualifier_values(qualifier_key!("product"))
+                    .next()
+                    .map(|v| v.to_string().replace('\n', ""))
+                    .unwrap_or_default();
+
+                let nt = String::from_utf8(
+                    seq.extract_location(&f.location.clone()).unwrap().to_vec(),
+                )
+                .unwrap()
+                .to_ascii_uppercase();
+
+                let table_id = table_override.unwrap_or_else(|| {
+                    f.qualifier_values(qualifier_key!("transl_table"))
+                        .next()
+                        .and_then(|v| v.parse::<u8>().ok())
+                        .unwrap_or(11)
+                });
+                let (code, starts) = lookup_table(table_id);
+
+                let mut peptide = String::new();
+                for (i, codon) in nt.as_bytes().chunks_exact(3).enumerate() {
+                    match translate(codon, i, code, starts) {
+                        Ok(aa) => peptide.push(aa),
+                        Err(e) => {
+                            println!("Error: {}", e);
+                            process::exit(1);
+                        }
+                    }
+                }
+
+                write_fasta_record(&mut nt_out, &protein_id, &product, &nt).unwrap();
+                write_fasta_record(&mut aa_out, &protein_id, &product, &peptide).unwrap();
+
+                n_cds += 1;
+            }
+
+            n_records += 1;
+        }
+
+        println!(
+            "Wrote {} CDS from {} record(s) to '{}' and '{}'.",
+            n_cds,
+            n_records,
+            nt_path.display(),
+            aa_path.display()
+        );
+        return;
+    }
+
+    if matches.is_present("six-frame") {
+        let show_orfs = matches.is_present("orfs");
+        let table_id = table_override.unwrap_or(11);
+        let (code, starts) = lookup_table(table_id);
+
+        let file = File::open(filename).unwrap();
+        for r in SeqReader::new(file) {
+            let seq = r.unwrap();
+            let record_name = seq.name.clone().unwrap();
+            let s = String::from_utf8(seq.seq.clone())
+                .unwrap()
+                .to_ascii_uppercase();
+
+            println!("\nRecord name: {} ({} bp)", record_name, s.len());
+            match six_frame_translate(&s, one_letter, code, starts, show_orfs) {
+                Ok(()) => (),
+                Err(e) => {
+                    println!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+
+        return;
+    }
+
+    if matches.is_present("stats") {
+        let file = File::open(filename).unwrap();
+        for r in SeqReader::new(file) {
+            let seq = r.unwrap();
+            let record_name = seq.name.clone().unwrap();
+            let s = String::from_utf8(seq.seq.clone())
+                .unwrap()
+                .to_ascii_uppercase();
+
+            let table_id = table_override.unwrap_or(11);
+            let (code, starts) = lookup_table(table_id);
+            match print_stats(&format!("Record: {}", record_name), &s, code, starts) {
+                Ok(()) => (),
+                Err(e) => {
+                    println!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+
+            for f in &seq.features {
+                if f.kind != feature_kind!("CDS") {
+                    continue;
+                }
+
+                let protein_id = f
+                    .qualifier_values(qualifier_key!("protein_id"))
+                    .next()
+                    .map(|v| v.to_string().replace('\n', ""))
+                    .unwrap_or_else(|| "unknown".to_string());
+
+                let cds_table_id = table_override.unwrap_or_else(|| {
+                    f.qualifier_values(qualifier_key!("transl_table"))
+                        .next()
+                        .and_then(|v| v.parse::<u8>().ok())
+                        .unwrap_or(11)
+                });
+                let (cds_code, cds_starts) = lookup_table(cds_table_id);
+
+                let cds_seq = String::from_utf8(
+                    seq.extract_location(&f.location.clone()).unwrap().to_vec(),
+                )
+                .unwrap()
+                .to_ascii_uppercase();
+
+                match print_stats(&format!("CDS: {}", protein_id), &cds_seq, cds_code, cds_starts) {
+                    Ok(()) => (),
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+
+        return;
+    }
+
+    if matches.is_present("unique") {
+        let both_strands = matches.is_present("both-strands");
+        let window = match matches.value_of("window") {
+            None => None,
+            Some(spec) => match parse_window(spec) {
+                Ok(w) => Some(w),
+                Err(e) => {
+                    println!("Invalid --window '{}': {}", spec, e);
+                    process::exit(1);
+                }
+            },
+        };
+
+        let file = File::open(filename).unwrap();
+        for r in SeqReader::new(file) {
+            let seq = r.unwrap();
+            let record_name = seq.name.clone().unwrap();
+            let full_seq = String::from_utf8(seq.seq.clone())
+                .unwrap()
+                .to_ascii_uppercase();
+
+            if let Some((start, end)) = window {
+                if start > full_seq.len() {
+                    println!(
+                        "Invalid --window '{}-{}': start is past the end of {} ({} bp)",
+                        start,
+                        end,
+                        record_name,
+                        full_seq.len()
+                    );
+                    process::exit(1);
+                }
+
+                let end = cmp::min(end, full_seq.len());
+                let region = &full_seq[(start - 1)..end];
+                report_unique_subsequence(
+                    &format!("{} [{}-{}]", record_name, start, end),
+                    region,
+                    both_strands,
+                );
+                continue;
+            }
+
+            for f in &seq.features {
+                if f.kind != feature_kind!("CDS") {
+                    continue;
+                }
+
+                let protein_id = f
+                    .qualifier_values(qualifier_key!("protein_id"))
+                    .next()
+                    .map(|v| v.to_string().replace('\n', ""))
+                    .unwrap_or_else(|| "unknown".to_string());
+
+                let cds_seq = String::from_utf8(
+                    seq.extract_location(&f.location.clone()).unwrap().to_vec(),
+                )
+                .unwrap()
+                .to_ascii_uppercase();
+
+                report_unique_subsequence(&protein_id, &cds_seq, both_strands);
+            }
+        }
+
+        return;
+    }
+
     println!("\nReading records from file '{}'...", filename);
 
     let file = File::open(filename).unwrap();
@@ -356,10 +1159,19 @@ fn main() {
                     .unwrap()
                     .to_ascii_uppercase();
 
+                    // default to table 11 unless the user passed --table or
+                    // this CDS carries its own /transl_table qualifier
+                    let table_id = table_override.unwrap_or_else(|| {
+                        f.qualifier_values(qualifier_key!("transl_table"))
+                            .next()
+                            .and_then(|v| v.parse::<u8>().ok())
+                            .unwrap_or(11)
+                    });
+                    let (code, starts) = lookup_table(table_id);
+
                     println!("\n{}: {}", genes[selection], descs[selection]);
-                    // print_seq(&s).expect("Error in print_seq().");
-                    let _ = match print_seq(&s, one_letter) {
-                        Ok(i) => i,
+                    match print_seq(&s, one_letter, code, starts) {
+                        Ok(()) => (),
                         Err(e) => {
                             println!("Error: {}", e);
                             process::exit(1);
@@ -380,38 +1192,82 @@ mod tests {
 
     #[test]
     fn test_translate_atg() {
-        assert!(matches!(translate(b"ATG", 1), Ok('M')));
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"ATG", 1, code, starts), Ok('M')));
     }
 
     #[test]
     fn test_translate_atg_as_start() {
-        assert!(matches!(translate(b"ATG", 0), Ok('M')));
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"ATG", 0, code, starts), Ok('M')));
     }
 
     #[test]
     fn test_translate_gtg() {
-        assert!(matches!(translate(b"GTG", 1), Ok('V')));
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"GTG", 1, code, starts), Ok('V')));
     }
 
     #[test]
     fn test_translate_gtg_as_start() {
-        assert!(matches!(translate(b"GTG", 0), Ok('M')));
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"GTG", 0, code, starts), Ok('M')));
     }
 
     #[test]
     fn test_translate_tag() {
-        assert!(matches!(translate(b"TAG", 1), Ok('*')));
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"TAG", 1, code, starts), Ok('*')));
     }
 
     #[test]
     fn test_translate_ttt() {
-        assert!(matches!(translate(b"TTT", 1), Ok('F')));
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"TTT", 1, code, starts), Ok('F')));
+    }
+
+    #[test]
+    fn test_translate_degenerate_gtn_is_val() {
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"GTN", 1, code, starts), Ok('V')));
+    }
+
+    #[test]
+    fn test_translate_degenerate_ath_is_ile() {
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(translate(b"ATH", 1, code, starts), Ok('I')));
+    }
+
+    #[test]
+    fn test_translate_degenerate_disagreement_is_unknown() {
+        let (code, starts) = lookup_table(11);
+        // MGN expands to AGT/AGC (Ser) and AGA/AGG/CGN (Arg); the
+        // expansions disagree, so it should fall back to 'X'
+        assert!(matches!(translate(b"MGN", 1, code, starts), Ok('X')));
+    }
+
+    #[test]
+    fn test_translate_degenerate_start_codon() {
+        let (code, starts) = lookup_table(11);
+        // ATT/ATC/ATA are all alternative starts under table 11, so ATH at
+        // position 0 should agree on Met rather than falling back to Ile
+        assert!(matches!(translate(b"ATH", 0, code, starts), Ok('M')));
+    }
+
+    #[test]
+    fn test_translate_bad_nucleotide() {
+        let (code, starts) = lookup_table(11);
+        assert!(matches!(
+            translate(b"AT-", 1, code, starts),
+            Err(Error::BadNucleotide(_))
+        ));
     }
 
     #[test]
     fn test_one_to_three_translate() {
+        let (code, starts) = lookup_table(11);
         assert_eq!(
-            three_letter_code(translate(b"ATG", 0).unwrap()).unwrap(),
+            three_letter_code(translate(b"ATG", 0, code, starts).unwrap()).unwrap(),
             "Met"
         );
     }
@@ -441,4 +1297,89 @@ mod tests {
     fn test_count_digits2() {
         assert_eq!(count_digits(2500), 4);
     }
+
+    #[test]
+    fn test_reverse_complement() {
+        assert_eq!(reverse_complement("ATGC"), "GCAT");
+    }
+
+    #[test]
+    fn test_reverse_complement_ambiguous() {
+        assert_eq!(reverse_complement("ATGCRYN"), "NRYGCAT");
+    }
+
+    #[test]
+    fn test_find_orfs_basic() {
+        // M-S-*  followed by M-*
+        assert_eq!(find_orfs("MS*M*"), vec![(0, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn test_find_orfs_none() {
+        assert_eq!(find_orfs("SSS"), vec![]);
+    }
+
+    #[test]
+    fn test_composition_stats_gc_content() {
+        let (counts, gc) = composition_stats("GGCCAATT");
+        assert_eq!(counts[&'G'], 2);
+        assert_eq!(counts[&'A'], 2);
+        assert_eq!(gc, 0.5);
+    }
+
+    #[test]
+    fn test_composition_stats_ignores_ambiguous() {
+        let (_, gc) = composition_stats("GGNN");
+        assert_eq!(gc, 1.0);
+    }
+
+    #[test]
+    fn test_codon_usage_groups_by_amino_acid() {
+        let (code, starts) = lookup_table(11);
+        let usage = codon_usage("TTTTTC", code, starts).unwrap();
+        let phe = &usage[&'F'];
+        assert_eq!(phe.len(), 2);
+        assert!(phe.iter().all(|(_, count, freq)| *count == 1 && *freq == 0.5));
+    }
+
+    #[test]
+    fn test_shortest_unique_subsequence() {
+        // the only 'G' in "GATTACA" is globally unique on its own
+        assert_eq!(
+            shortest_unique_subsequence("GATTACA"),
+            Some(("G".to_string(), 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_shortest_unique_subsequence_both_strands() {
+        // "AAAC"'s reverse complement is "GTTT"; the lone 'C' doesn't
+        // appear on either strand elsewhere
+        assert_eq!(
+            shortest_unique_subsequence_both_strands("AAAC"),
+            Some(("C".to_string(), 4, 1))
+        );
+    }
+
+    #[test]
+    fn test_shortest_unique_subsequence_both_strands_cds_length() {
+        // regression: a candidate length was previously bounded against the
+        // combined `s$rc(s)` buffer instead of `s` itself, so a match near
+        // the tail of `s` could spill into the separator/RC portion and
+        // panic when sliced out of the (non-combined) original string
+        let s = "ATGACGTACGGGCATGCATTAGGGCCTAAGCTTGACCATGGATCCGAATTCGTAACGGATCCCAAGCTTGGTACCGAGCTCGGATCC".repeat(12);
+        let (kmer, start, len) = shortest_unique_subsequence_both_strands(&s).unwrap();
+        assert_eq!(kmer.len(), len);
+        assert!(start >= 1 && start + len - 1 <= s.len());
+    }
+
+    #[test]
+    fn test_parse_window_valid() {
+        assert_eq!(parse_window("100-250"), Ok((100, 250)));
+    }
+
+    #[test]
+    fn test_parse_window_invalid() {
+        assert!(parse_window("250-100").is_err());
+    }
 }